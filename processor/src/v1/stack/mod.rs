@@ -1,6 +1,12 @@
 use super::{BaseElement, ExecutionError, FieldElement, ProgramInputs, StackTrace, STACK_TOP_SIZE};
 use core::{cmp, convert::TryInto};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum stack depth allowed when a program does not specify its own limit.
+const DEFAULT_MAX_STACK_DEPTH: usize = 1024;
+
 // STACK
 // ================================================================================================
 
@@ -10,6 +16,8 @@ pub struct Stack {
     trace: StackTrace,
     overflow: Vec<BaseElement>,
     depth: usize,
+    max_depth: usize,
+    max_steps: Option<usize>,
 }
 
 impl Stack {
@@ -32,9 +40,20 @@ impl Stack {
             trace: trace.try_into().expect("failed to convert vector to array"),
             overflow: Vec::new(),
             depth: 0,
+            max_depth: inputs.max_stack_depth().unwrap_or(DEFAULT_MAX_STACK_DEPTH),
+            max_steps: None,
         }
     }
 
+    /// Sets the maximum number of clock cycles this stack is allowed to advance through.
+    ///
+    /// Once `current_step()` reaches this budget, `advance_clock` (and, by extension, `finalize`)
+    /// returns `ExecutionError::CycleLimitExceeded` instead of advancing further.
+    #[allow(dead_code)]
+    pub fn set_max_steps(&mut self, max_steps: usize) {
+        self.max_steps = Some(max_steps);
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -44,6 +63,12 @@ impl Stack {
         self.depth
     }
 
+    /// Returns the maximum depth this stack is allowed to grow to.
+    #[allow(dead_code)]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
     /// Returns the current step of the execution trace.
     #[allow(dead_code)]
     pub fn current_step(&self) -> usize {
@@ -158,7 +183,11 @@ impl Stack {
     ///
     /// If stack depth grows beyond 16 items, the additional item is pushed into the overflow
     /// stack.
-    pub fn shift_right(&mut self, start_pos: usize) {
+    ///
+    /// # Errors
+    /// Returns an error if the stack is already at its maximum depth, since growing it further
+    /// would exceed the bound enforced for `op`.
+    pub fn shift_right(&mut self, start_pos: usize, op: &'static str) -> Result<(), ExecutionError> {
         debug_assert!(
             start_pos < STACK_TOP_SIZE,
             "start position cannot exceed stack top size"
@@ -168,6 +197,10 @@ impl Stack {
             "start position cannot exceed current depth"
         );
 
+        if self.depth >= self.max_depth {
+            return Err(ExecutionError::StackOverflow(op, self.step));
+        }
+
         const MAX_TOP_IDX: usize = STACK_TOP_SIZE - 1;
         match self.depth {
             0 => {} // if the stack is empty, do nothing
@@ -186,18 +219,39 @@ impl Stack {
         }
 
         self.depth += 1;
+
+        Ok(())
     }
 
-    // Increments the clock cycle.
-    pub fn advance_clock(&mut self) {
+    /// Increments the clock cycle.
+    ///
+    /// # Errors
+    /// Returns an error if advancing would take `current_step()` past the configured
+    /// `max_steps` budget.
+    pub fn advance_clock(&mut self) -> Result<(), ExecutionError> {
+        if let Some(max_steps) = self.max_steps {
+            if self.step >= max_steps {
+                return Err(ExecutionError::CycleLimitExceeded(self.step));
+            }
+        }
+
         self.step += 1;
+
+        Ok(())
     }
 
-    pub fn finalize(&mut self) {
+    /// Pads the trace with copies of the current state until it is full.
+    ///
+    /// # Errors
+    /// Returns an error if padding the trace would advance the clock past the configured
+    /// `max_steps` budget.
+    pub fn finalize(&mut self) -> Result<(), ExecutionError> {
         for _ in self.step..self.trace_length() - 1 {
             self.copy_state(0);
-            self.advance_clock();
+            self.advance_clock()?;
         }
+
+        Ok(())
     }
 
     // UTILITY METHODS
@@ -226,4 +280,139 @@ impl Stack {
             Ok(())
         }
     }
+
+    /// Returns an error if executing `op` would underflow or overflow the stack.
+    ///
+    /// `pop` is the number of items `op` reads off the top of the stack, and `push` is the
+    /// number of items it places back. This combines the `check_depth` underflow check with a
+    /// check against `max_depth`, so the full effect of an operation can be validated before any
+    /// `shift_left`/`shift_right`/`set` calls mutate the trace.
+    ///
+    /// The overflow check is against the net depth after the operation (`depth - pop + push`).
+    #[allow(dead_code)]
+    pub fn check_pop_push(
+        &self,
+        pop: usize,
+        push: usize,
+        op: &'static str,
+    ) -> Result<(), ExecutionError> {
+        self.check_depth(pop, op)?;
+
+        if self.depth - pop + push > self.max_depth {
+            return Err(ExecutionError::StackOverflow(op, self.step));
+        }
+
+        Ok(())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACE_LENGTH: usize = 16;
+
+    /// Builds a stack with the given `max_depth` and `max_steps`, bypassing `ProgramInputs` so
+    /// tests can exercise small, easy-to-reason-about limits.
+    fn new_stack(max_depth: usize, max_steps: Option<usize>) -> Stack {
+        let mut stack = Stack::new(&ProgramInputs::none(), TRACE_LENGTH);
+        stack.max_depth = max_depth;
+        stack.max_steps = max_steps;
+        stack
+    }
+
+    #[test]
+    fn shift_right_stack_overflow() {
+        let mut stack = new_stack(2, None);
+        stack.depth = 2;
+
+        let step = stack.current_step();
+        let result = stack.shift_right(0, "test");
+        assert!(matches!(result, Err(ExecutionError::StackOverflow("test", s)) if s == step));
+        assert_eq!(stack.depth(), 2, "depth must not change on a rejected push");
+    }
+
+    #[test]
+    fn shift_right_within_max_depth() {
+        let mut stack = new_stack(2, None);
+        stack.depth = 1;
+
+        assert!(stack.shift_right(0, "test").is_ok());
+        assert_eq!(stack.depth(), 2);
+    }
+
+    #[test]
+    fn check_pop_push_underflow() {
+        let mut stack = new_stack(4, None);
+        stack.depth = 1;
+
+        let step = stack.current_step();
+        let result = stack.check_pop_push(2, 0, "test");
+        assert!(matches!(result, Err(ExecutionError::StackUnderflow("test", s)) if s == step));
+    }
+
+    #[test]
+    fn check_pop_push_pop_just_satisfiable() {
+        let mut stack = new_stack(4, None);
+        stack.depth = 2;
+
+        assert!(stack.check_pop_push(2, 0, "test").is_ok());
+    }
+
+    #[test]
+    fn check_pop_push_net_depth_at_max() {
+        let mut stack = new_stack(4, None);
+        stack.depth = 3;
+
+        // net depth (3 - 0 + 1 = 4) lands exactly on max_depth.
+        assert!(stack.check_pop_push(0, 1, "test").is_ok());
+    }
+
+    #[test]
+    fn check_pop_push_net_depth_one_over_max() {
+        let mut stack = new_stack(4, None);
+        stack.depth = 4;
+
+        let step = stack.current_step();
+        let result = stack.check_pop_push(0, 1, "test");
+        assert!(matches!(result, Err(ExecutionError::StackOverflow("test", s)) if s == step));
+    }
+
+    #[test]
+    fn check_pop_push_accepts_net_depth_at_max_with_pop() {
+        let mut stack = new_stack(4, None);
+        stack.depth = 4;
+
+        // net depth (4 - 1 + 1 = 4) lands exactly on max_depth, even though depth + push = 5.
+        assert!(stack.check_pop_push(1, 1, "test").is_ok());
+    }
+
+    #[test]
+    fn advance_clock_cycle_limit_exceeded() {
+        let mut stack = new_stack(DEFAULT_MAX_STACK_DEPTH, Some(0));
+
+        let result = stack.advance_clock();
+        assert!(matches!(result, Err(ExecutionError::CycleLimitExceeded(0))));
+        assert_eq!(stack.current_step(), 0, "step must not advance past the budget");
+    }
+
+    #[test]
+    fn advance_clock_within_budget() {
+        let mut stack = new_stack(DEFAULT_MAX_STACK_DEPTH, Some(1));
+
+        assert!(stack.advance_clock().is_ok());
+        assert_eq!(stack.current_step(), 1);
+    }
+
+    #[test]
+    fn finalize_respects_max_steps() {
+        let mut stack = new_stack(DEFAULT_MAX_STACK_DEPTH, Some(1));
+
+        // TRACE_LENGTH - 1 steps are needed to finalize, but the budget only allows 1.
+        let result = stack.finalize();
+        assert!(matches!(result, Err(ExecutionError::CycleLimitExceeded(1))));
+    }
 }
\ No newline at end of file